@@ -1,11 +1,15 @@
 use crate::{mat::MatLayout, to_result, utils::path_to_cstring, Error, Mat, Parameters, Result};
 use lgbm_sys::{
-    DatasetHandle, LGBM_DatasetCreateFromFile, LGBM_DatasetCreateFromMat, LGBM_DatasetDumpText,
-    LGBM_DatasetFree, LGBM_DatasetGetField, LGBM_DatasetGetNumData, LGBM_DatasetGetNumFeature,
-    LGBM_DatasetSetField, C_API_DTYPE_FLOAT32, C_API_DTYPE_FLOAT64, C_API_DTYPE_INT32,
+    DatasetHandle, LGBM_DatasetCreateFromCSC, LGBM_DatasetCreateFromCSR,
+    LGBM_DatasetCreateFromFile, LGBM_DatasetCreateFromMat, LGBM_DatasetCreateFromSampledColumn,
+    LGBM_DatasetDumpText, LGBM_DatasetFree, LGBM_DatasetGetFeatureNames, LGBM_DatasetGetField,
+    LGBM_DatasetGetNumData, LGBM_DatasetGetNumFeature, LGBM_DatasetPushRows,
+    LGBM_DatasetPushRowsByCSR, LGBM_DatasetSetFeatureNames, LGBM_DatasetSetField,
+    LGBM_DatasetUpdateParamChecking, C_API_DTYPE_FLOAT32, C_API_DTYPE_FLOAT64, C_API_DTYPE_INT32,
     C_API_DTYPE_INT64,
 };
 use std::{
+    ffi::{CStr, CString},
     marker::PhantomData,
     os::raw::{c_int, c_void},
     path::Path,
@@ -47,6 +51,21 @@ pub trait FeatureData: Data {}
 impl FeatureData for f32 {}
 impl FeatureData for f64 {}
 
+/// An integer type usable for CSR/CSC index-pointer arrays.
+pub trait IndptrData: Data + Copy {
+    fn to_usize(self) -> usize;
+}
+impl IndptrData for i32 {
+    fn to_usize(self) -> usize {
+        self as usize
+    }
+}
+impl IndptrData for i64 {
+    fn to_usize(self) -> usize {
+        self as usize
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Field<T> {
     name: &'static [u8],
@@ -77,7 +96,19 @@ impl Field<i32> {
 }
 
 /// Owned [DatasetHandle](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.DatasetHandle)
-pub struct Dataset(pub(crate) DatasetHandle);
+pub struct Dataset {
+    pub(crate) handle: DatasetHandle,
+    /// `num_total_row` declared when the dataset was created empty (e.g. via
+    /// [`Dataset::from_sampled_columns`]), used to bound [`Dataset::push_rows`]
+    /// / [`Dataset::push_rows_by_csr`]. `None` for datasets that already hold
+    /// their full row data on construction.
+    num_total_row: Option<usize>,
+    /// Highest end row (exclusive) reached so far by `push_rows`/`push_rows_by_csr`.
+    rows_pushed: usize,
+    /// The parameters the dataset was constructed with, kept around as the
+    /// "old_parameters" side of [`Dataset::check_param_compatibility`].
+    parameters: Parameters,
+}
 
 impl Dataset {
     /// [LGBM_DatasetCreateFromFile](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetCreateFromFile)
@@ -96,7 +127,7 @@ impl Dataset {
                 &mut handle,
             ))?;
         }
-        Ok(Self(handle))
+        Ok(Self::from_handle(handle, parameters))
     }
 
     /// [LGBM_DatasetCreateFromMat](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetCreateFromMat)
@@ -119,7 +150,91 @@ impl Dataset {
                 &mut handle,
             ))?;
         }
-        Ok(Self(handle))
+        Ok(Self::from_handle(handle, parameters))
+    }
+
+    /// Builds a dataset from a sparse matrix in CSR (row-compressed) layout,
+    /// without densifying it first.
+    ///
+    /// `indptr` has one entry per row plus a trailing entry, `indices`/`data`
+    /// hold the column index and value of each non-zero entry, and `num_col`
+    /// is the number of columns of the full (logical) matrix.
+    ///
+    /// [LGBM_DatasetCreateFromCSR](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetCreateFromCSR)
+    #[doc(alias = "LGBM_DatasetCreateFromCSR")]
+    pub fn from_csr<P: IndptrData, T: FeatureData>(
+        indptr: &[P],
+        indices: &[i32],
+        data: &[T],
+        num_col: usize,
+        reference: Option<&Dataset>,
+        parameters: &Parameters,
+    ) -> Result<Self> {
+        if indices.len() != data.len() {
+            return Err(Error::from_message(
+                "indices and data must have the same length",
+            ));
+        }
+        check_indptr_consistent(indptr, indices.len())?;
+        let mut handle = null_mut();
+        unsafe {
+            to_result(LGBM_DatasetCreateFromCSR(
+                P::as_data_ptr(indptr.as_ptr()),
+                P::DATA_TYPE,
+                indices.as_ptr(),
+                T::as_data_ptr(data.as_ptr()),
+                T::DATA_TYPE,
+                indptr.len().try_into()?,
+                data.len().try_into()?,
+                num_col.try_into()?,
+                parameters.to_cstring()?.as_ptr(),
+                to_dataset_handle(reference),
+                &mut handle,
+            ))?;
+        }
+        Ok(Self::from_handle(handle, parameters))
+    }
+
+    /// Builds a dataset from a sparse matrix in CSC (column-compressed)
+    /// layout, without densifying it first.
+    ///
+    /// `col_ptr` has one entry per column plus a trailing entry,
+    /// `indices`/`data` hold the row index and value of each non-zero entry,
+    /// and `num_row` is the number of rows of the full (logical) matrix.
+    ///
+    /// [LGBM_DatasetCreateFromCSC](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetCreateFromCSC)
+    #[doc(alias = "LGBM_DatasetCreateFromCSC")]
+    pub fn from_csc<P: IndptrData, T: FeatureData>(
+        col_ptr: &[P],
+        indices: &[i32],
+        data: &[T],
+        num_row: usize,
+        reference: Option<&Dataset>,
+        parameters: &Parameters,
+    ) -> Result<Self> {
+        if indices.len() != data.len() {
+            return Err(Error::from_message(
+                "indices and data must have the same length",
+            ));
+        }
+        check_indptr_consistent(col_ptr, indices.len())?;
+        let mut handle = null_mut();
+        unsafe {
+            to_result(LGBM_DatasetCreateFromCSC(
+                P::as_data_ptr(col_ptr.as_ptr()),
+                P::DATA_TYPE,
+                indices.as_ptr(),
+                T::as_data_ptr(data.as_ptr()),
+                T::DATA_TYPE,
+                col_ptr.len().try_into()?,
+                data.len().try_into()?,
+                num_row.try_into()?,
+                parameters.to_cstring()?.as_ptr(),
+                to_dataset_handle(reference),
+                &mut handle,
+            ))?;
+        }
+        Ok(Self::from_handle(handle, parameters))
     }
 
     /// [LGBM_DatasetSetField](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetSetField)
@@ -127,7 +242,7 @@ impl Dataset {
     pub fn set_field<T: Data>(&mut self, field: Field<T>, data: &[T]) -> Result<()> {
         unsafe {
             to_result(LGBM_DatasetSetField(
-                self.0,
+                self.handle,
                 field.name_ptr(),
                 data.as_ptr() as *const c_void,
                 data.len().try_into()?,
@@ -144,7 +259,7 @@ impl Dataset {
             let mut out_ptr = null();
             let mut out_type = 0;
             to_result(LGBM_DatasetGetField(
-                self.0,
+                self.handle,
                 field.name_ptr(),
                 &mut out_len,
                 &mut out_ptr,
@@ -162,7 +277,7 @@ impl Dataset {
     pub fn get_num_feature(&self) -> Result<usize> {
         let mut num_feature = 0;
         unsafe {
-            to_result(LGBM_DatasetGetNumFeature(self.0, &mut num_feature))?;
+            to_result(LGBM_DatasetGetNumFeature(self.handle, &mut num_feature))?;
         }
         Ok(num_feature as usize)
     }
@@ -172,17 +287,287 @@ impl Dataset {
     pub fn get_num_data(&self) -> Result<usize> {
         let mut num_data = 0;
         unsafe {
-            to_result(LGBM_DatasetGetNumData(self.0, &mut num_data))?;
+            to_result(LGBM_DatasetGetNumData(self.handle, &mut num_data))?;
         }
         Ok(num_data as usize)
     }
 
+    /// [LGBM_DatasetSetFeatureNames](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetSetFeatureNames)
+    #[doc(alias = "LGBM_DatasetSetFeatureNames")]
+    pub fn set_feature_names(&mut self, names: &[&str]) -> Result<()> {
+        let num_feature = self.get_num_feature()?;
+        if names.len() != num_feature {
+            return Err(Error::from_message(
+                "number of feature names does not match the dataset's number of features",
+            ));
+        }
+        let names = names
+            .iter()
+            .map(|name| CString::new(*name))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|_| Error::from_message("feature name must not contain a null byte"))?;
+        let name_ptrs: Vec<*const i8> = names.iter().map(|name| name.as_ptr()).collect();
+        unsafe {
+            to_result(LGBM_DatasetSetFeatureNames(
+                self.handle,
+                name_ptrs.as_ptr() as *mut *const i8,
+                name_ptrs.len().try_into()?,
+            ))
+        }
+    }
+
+    /// [LGBM_DatasetGetFeatureNames](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetGetFeatureNames)
+    #[doc(alias = "LGBM_DatasetGetFeatureNames")]
+    pub fn get_feature_names(&self) -> Result<Vec<String>> {
+        let num_feature = self.get_num_feature()?;
+
+        // Start with a generously-sized buffer per name; LightGBM reports the
+        // longest name's true length via `out_buffer_len` only once it has
+        // actually copied into real buffers, so if that comes back bigger
+        // than what we guessed, reallocate and ask again.
+        let mut buffer_len: usize = 256;
+        loop {
+            let mut out_len = 0;
+            let mut out_buffer_len = 0;
+            let mut buffers: Vec<Vec<u8>> =
+                (0..num_feature).map(|_| vec![0u8; buffer_len]).collect();
+            let mut name_ptrs: Vec<*mut i8> = buffers
+                .iter_mut()
+                .map(|buffer| buffer.as_mut_ptr() as *mut i8)
+                .collect();
+            unsafe {
+                to_result(LGBM_DatasetGetFeatureNames(
+                    self.handle,
+                    num_feature.try_into()?,
+                    &mut out_len,
+                    buffer_len,
+                    &mut out_buffer_len,
+                    name_ptrs.as_mut_ptr(),
+                ))?;
+            }
+
+            if out_buffer_len <= buffer_len {
+                return buffers
+                    .into_iter()
+                    .map(|buffer| {
+                        CStr::from_bytes_until_nul(&buffer)
+                            .map_err(|_| {
+                                Error::from_message("feature name is not null-terminated")
+                            })?
+                            .to_str()
+                            .map(str::to_owned)
+                            .map_err(|_| Error::from_message("feature name is not valid UTF-8"))
+                    })
+                    .collect();
+            }
+            buffer_len = out_buffer_len;
+        }
+    }
+
+    /// Builds a dataset's bin mappers from a per-feature subsample, without ever
+    /// materializing the full matrix in memory.
+    ///
+    /// `values`/`indices` hold, for each of the `values.len()` features, the
+    /// sampled non-zero values and the row index each one came from (zeros are
+    /// omitted). `num_per_col` gives the number of sampled entries per feature,
+    /// `num_sample_row` the number of rows in the sample and
+    /// `num_total_row` the number of rows the finished dataset will eventually
+    /// hold. The returned [`Dataset`] has finalized bin boundaries but no row
+    /// data yet; fill it with [`Dataset::push_rows`] or
+    /// [`Dataset::push_rows_by_csr`].
+    ///
+    /// [LGBM_DatasetCreateFromSampledColumn](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetCreateFromSampledColumn)
+    #[doc(alias = "LGBM_DatasetCreateFromSampledColumn")]
+    pub fn from_sampled_columns(
+        values: &[&[f64]],
+        indices: &[&[i32]],
+        num_per_col: &[i32],
+        num_sample_row: usize,
+        num_total_row: usize,
+        parameters: &Parameters,
+    ) -> Result<Self> {
+        let ncol = values.len();
+        if indices.len() != ncol || num_per_col.len() != ncol {
+            return Err(Error::from_message(
+                "values, indices and num_per_col must all have one entry per column",
+            ));
+        }
+        for ((col, value_col), index_col) in values.iter().enumerate().zip(indices.iter()) {
+            if value_col.len() != index_col.len() {
+                return Err(Error::from_message(
+                    "sampled values and indices must have the same length for each column",
+                ));
+            }
+            if value_col.len() != num_per_col[col] as usize {
+                return Err(Error::from_message(
+                    "num_per_col does not match the provided sample length for a column",
+                ));
+            }
+        }
+
+        let mut value_ptrs: Vec<*mut f64> =
+            values.iter().map(|col| col.as_ptr() as *mut f64).collect();
+        let mut index_ptrs: Vec<*mut i32> =
+            indices.iter().map(|col| col.as_ptr() as *mut i32).collect();
+
+        let mut handle = null_mut();
+        unsafe {
+            to_result(LGBM_DatasetCreateFromSampledColumn(
+                value_ptrs.as_mut_ptr(),
+                index_ptrs.as_mut_ptr(),
+                ncol.try_into()?,
+                num_per_col.as_ptr(),
+                num_sample_row.try_into()?,
+                num_total_row.try_into()?,
+                parameters.to_cstring()?.as_ptr(),
+                &mut handle,
+            ))?;
+        }
+        Ok(Self {
+            handle,
+            num_total_row: Some(num_total_row),
+            rows_pushed: 0,
+            parameters: parameters.clone(),
+        })
+    }
+
+    /// Copies a block of dense rows into the dataset's internal binned storage,
+    /// starting at `start_row`.
+    ///
+    /// Intended for filling a dataset created empty (e.g. by
+    /// [`Dataset::from_sampled_columns`]) in fixed-size batches, so the raw
+    /// data never has to reside in memory all at once. Rejects a push whose
+    /// rows (`start_row` plus the block's row count) would extend past the
+    /// `num_total_row` declared at construction.
+    ///
+    /// [LGBM_DatasetPushRows](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetPushRows)
+    #[doc(alias = "LGBM_DatasetPushRows")]
+    pub fn push_rows<T: FeatureData, L: MatLayout>(
+        &mut self,
+        data: &Mat<T, L>,
+        start_row: usize,
+    ) -> Result<()> {
+        let num_feature = self.get_num_feature()?;
+        if data.ncol() != num_feature {
+            return Err(Error::from_message(
+                "number of columns does not match the dataset's number of features",
+            ));
+        }
+        let rows_pushed = self.check_push_bounds(start_row, data.nrow())?;
+        unsafe {
+            to_result(LGBM_DatasetPushRows(
+                self.handle,
+                T::as_data_ptr(data.as_ptr()),
+                T::DATA_TYPE,
+                data.nrow().try_into()?,
+                data.ncol().try_into()?,
+                start_row.try_into()?,
+            ))?;
+        }
+        self.rows_pushed = rows_pushed;
+        Ok(())
+    }
+
+    /// Copies a block of CSR-encoded rows into the dataset's internal binned
+    /// storage, starting at `start_row`.
+    ///
+    /// `indptr` has one entry per row plus a trailing entry (standard CSR),
+    /// `indices`/`data` hold the column index and value of each non-zero
+    /// entry. See [`Dataset::push_rows`] for the chunked-ingestion use case
+    /// and the `num_total_row` bound it enforces.
+    ///
+    /// [LGBM_DatasetPushRowsByCSR](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetPushRowsByCSR)
+    #[doc(alias = "LGBM_DatasetPushRowsByCSR")]
+    pub fn push_rows_by_csr<P: IndptrData, T: FeatureData>(
+        &mut self,
+        indptr: &[P],
+        indices: &[i32],
+        data: &[T],
+        num_col: usize,
+        start_row: usize,
+    ) -> Result<()> {
+        if indices.len() != data.len() {
+            return Err(Error::from_message(
+                "indices and data must have the same length",
+            ));
+        }
+        check_indptr_consistent(indptr, indices.len())?;
+        if num_col != self.get_num_feature()? {
+            return Err(Error::from_message(
+                "num_col does not match the dataset's number of features",
+            ));
+        }
+        let nrow = indptr.len() - 1;
+        let rows_pushed = self.check_push_bounds(start_row, nrow)?;
+        unsafe {
+            to_result(LGBM_DatasetPushRowsByCSR(
+                self.handle,
+                P::as_data_ptr(indptr.as_ptr()),
+                P::DATA_TYPE,
+                indices.as_ptr(),
+                T::as_data_ptr(data.as_ptr()),
+                T::DATA_TYPE,
+                indptr.len().try_into()?,
+                data.len().try_into()?,
+                num_col.try_into()?,
+                start_row.try_into()?,
+            ))?;
+        }
+        self.rows_pushed = rows_pushed;
+        Ok(())
+    }
+
+    /// Validates that pushing `nrow` rows starting at `start_row` does not
+    /// carry the dataset past the `num_total_row` declared at construction,
+    /// returning the highest end row (exclusive) reached once the push is done.
+    ///
+    /// Only datasets created empty (e.g. via [`Dataset::from_sampled_columns`])
+    /// declare a `num_total_row` to push against; a dataset that already holds
+    /// its full row data has no such bound to push rows into, so pushing onto
+    /// one is always rejected rather than silently unchecked.
+    fn check_push_bounds(&self, start_row: usize, nrow: usize) -> Result<usize> {
+        let num_total_row = self.num_total_row.ok_or_else(|| {
+            Error::from_message(
+                "push_rows is only valid on a dataset created empty, e.g. via from_sampled_columns",
+            )
+        })?;
+        let end_row = start_row
+            .checked_add(nrow)
+            .ok_or_else(|| Error::from_message("start_row + row count overflowed"))?;
+        if end_row > num_total_row {
+            return Err(Error::from_message(
+                "pushed rows would exceed the num_total_row declared at construction",
+            ));
+        }
+        Ok(end_row.max(self.rows_pushed))
+    }
+
+    /// Checks whether `new_parameters` is compatible with the parameters this
+    /// dataset was constructed with.
+    ///
+    /// [LGBM_DatasetUpdateParamChecking](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetUpdateParamChecking)
+    ///
+    /// LightGBM has no API to alter an already-constructed dataset's bins —
+    /// this only reports whether the change is safe (e.g. that
+    /// binning-affecting parameters like `max_bin` were not changed); an
+    /// incompatible change means the dataset must be rebuilt from scratch
+    /// with the new parameters rather than updated in place.
+    #[doc(alias = "LGBM_DatasetUpdateParamChecking")]
+    pub fn check_param_compatibility(&self, new_parameters: &Parameters) -> Result<()> {
+        unsafe {
+            to_result(LGBM_DatasetUpdateParamChecking(
+                self.parameters.to_cstring()?.as_ptr(),
+                new_parameters.to_cstring()?.as_ptr(),
+            ))
+        }
+    }
+
     /// [LGBM_DatasetDumpText](https://lightgbm.readthedocs.io/en/latest/C-API.html#c.LGBM_DatasetDumpText)
     #[doc(alias = "LGBM_DatasetDumpText")]
     pub fn dump_text(&self, path: &Path) -> Result<()> {
         unsafe {
             to_result(LGBM_DatasetDumpText(
-                self.0,
+                self.handle,
                 path_to_cstring(path)?.as_ptr(),
             ))
         }
@@ -191,15 +576,65 @@ impl Dataset {
 impl Drop for Dataset {
     fn drop(&mut self) {
         unsafe {
-            to_result(LGBM_DatasetFree(self.0)).unwrap();
+            to_result(LGBM_DatasetFree(self.handle)).unwrap();
         }
     }
 }
 
 fn to_dataset_handle(dataset: Option<&Dataset>) -> DatasetHandle {
     if let Some(dataset) = dataset {
-        dataset.0
+        dataset.handle
     } else {
         null_mut()
     }
 }
+
+/// Checks that a CSR/CSC index-pointer array is non-empty and that its
+/// trailing entry (the declared number of non-zero entries) agrees with the
+/// actual length of the paired indices/values arrays.
+fn check_indptr_consistent<P: IndptrData>(indptr: &[P], nnz: usize) -> Result<()> {
+    let last = indptr
+        .last()
+        .ok_or_else(|| Error::from_message("indptr must contain at least one entry"))?;
+    if last.to_usize() != nnz {
+        return Err(Error::from_message(
+            "indptr's trailing entry does not match the number of indices/data entries",
+        ));
+    }
+    Ok(())
+}
+
+impl Dataset {
+    /// Wraps a handle that already holds its full row data, so pushing rows
+    /// onto it afterwards is never expected.
+    fn from_handle(handle: DatasetHandle, parameters: &Parameters) -> Self {
+        Self {
+            handle,
+            num_total_row: None,
+            rows_pushed: 0,
+            parameters: parameters.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feature_names_round_trip() {
+        let mut dataset = Dataset::from_csr(
+            &[0i32, 2, 4],
+            &[0, 1, 0, 1],
+            &[1.0f64, 2.0, 3.0, 4.0],
+            2,
+            None,
+            &Parameters::default(),
+        )
+        .unwrap();
+
+        let names = ["feature_a", "feature_b"];
+        dataset.set_feature_names(&names).unwrap();
+        assert_eq!(dataset.get_feature_names().unwrap(), names);
+    }
+}